@@ -0,0 +1,98 @@
+//! Scaffolding for the `cuda`-feature backend requested for batched two-adic LDEs.
+//!
+//! Descoped: there is no device integration in this tree. Implementing a real CUDA backend
+//! needs a device crate (e.g. `cudarc`) and actual upload/NTT/download kernels, neither of which
+//! exist here — this file does not pretend otherwise. What it does provide is the trait surface
+//! (`TwoAdicLde`/`TwoAdicSubgroupLde`/`TwoAdicCosetLde`, matching `Radix2SubgroupLde`/
+//! `Radix2CosetLde` exactly) so a future device backend can be dropped in without touching call
+//! sites. Until that lands, `CudaSubgroupLde`/`CudaCosetLde` are plain CPU passthroughs to
+//! `Radix2SubgroupLde`/`Radix2CosetLde` — not an accelerated path, and the tests below are
+//! labeled accordingly rather than claimed as GPU correctness checks.
+
+use p3_field::TwoAdicField;
+use p3_matrix::dense::RowMajorMatrix;
+
+use crate::radix2::{Radix2CosetLde, Radix2SubgroupLde};
+use crate::{TwoAdicCosetLde, TwoAdicLde, TwoAdicSubgroupLde};
+
+/// CPU passthrough to `Radix2SubgroupLde`, standing in for the not-yet-implemented `cuda`
+/// backend. See the module docs: there is no device path behind this type yet.
+#[derive(Debug, Default)]
+pub struct CudaSubgroupLde;
+
+/// CPU passthrough to `Radix2CosetLde`, standing in for the not-yet-implemented `cuda` backend.
+/// See the module docs: there is no device path behind this type yet.
+#[derive(Debug, Default)]
+pub struct CudaCosetLde;
+
+impl<Val> TwoAdicLde<Val> for CudaSubgroupLde
+where
+    Val: TwoAdicField,
+{
+    fn lde_batch(&self, polys: RowMajorMatrix<Val>, added_bits: usize) -> RowMajorMatrix<Val> {
+        Radix2SubgroupLde.lde_batch(polys, added_bits)
+    }
+}
+
+impl<Val> TwoAdicLde<Val> for CudaCosetLde
+where
+    Val: TwoAdicField,
+{
+    fn lde_batch(&self, polys: RowMajorMatrix<Val>, added_bits: usize) -> RowMajorMatrix<Val> {
+        Radix2CosetLde.lde_batch(polys, added_bits)
+    }
+}
+
+impl<Val> TwoAdicSubgroupLde<Val> for CudaSubgroupLde where Val: TwoAdicField {}
+
+impl<Val> TwoAdicCosetLde<Val> for CudaCosetLde
+where
+    Val: TwoAdicField,
+{
+    fn shift(&self, _lde_bits: usize) -> Val {
+        Val::generator()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::distributions::Standard;
+    use rand::{thread_rng, Rng};
+
+    use super::{CudaCosetLde, CudaSubgroupLde};
+    use crate::naive::{NaiveCosetLde, NaiveSubgroupLde};
+    use crate::TwoAdicLde;
+
+    fn rand_matrix(rows: usize, cols: usize) -> RowMajorMatrix<Goldilocks> {
+        let values = thread_rng()
+            .sample_iter(Standard)
+            .take(rows * cols)
+            .collect();
+        RowMajorMatrix::new(values, cols)
+    }
+
+    // `CudaSubgroupLde`/`CudaCosetLde` are CPU passthroughs today (see the module docs — there
+    // is no device backend in this tree), so these only confirm the passthrough wiring is
+    // correct, i.e. that it's equivalent to `Radix2*`/`Naive*`. They are NOT a GPU correctness
+    // check: the request asked for a test that validates actual device output bit-for-bit
+    // against `NaiveSubgroupLde`/`Radix2SubgroupLde`, which requires a real kernel to exist
+    // first. When a `cuda`-feature device backend lands, add a `#[cfg(feature = "cuda")]` test
+    // alongside these that forces the device path and checks it against the same reference.
+    #[test]
+    fn cuda_subgroup_lde_passthrough_matches_naive() {
+        let polys = rand_matrix(8, 3);
+        let expected = NaiveSubgroupLde.lde_batch(polys.clone(), 2);
+        let actual = CudaSubgroupLde.lde_batch(polys, 2);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cuda_coset_lde_passthrough_matches_naive() {
+        let polys = rand_matrix(8, 3);
+        let expected = NaiveCosetLde.lde_batch(polys.clone(), 2);
+        let actual = CudaCosetLde.lde_batch(polys, 2);
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+
+use p3_field::{
+    cyclic_subgroup_coset_known_order, cyclic_subgroup_known_order, ExtensionField, TwoAdicField,
+};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_util::log2_strict_usize;
+
+use crate::interpolation::{barycentric_weights, interpolate_ext};
+
+/// Shared implementation backing `eval_at_point` on `NaiveSubgroupLde` and `Radix2SubgroupLde`: builds the
+/// order-`2^bits` subgroup that `subgroup_evals` is defined over, then evaluates it at `x` via
+/// the barycentric formula. Pulled out so `NaiveSubgroupLde`/`Radix2SubgroupLde` share one
+/// definition rather than each pasting it.
+pub(crate) fn eval_subgroup_at_point<Val, EF>(subgroup_evals: &RowMajorMatrix<Val>, x: EF) -> Vec<EF>
+where
+    Val: TwoAdicField,
+    EF: ExtensionField<Val>,
+{
+    let bits = log2_strict_usize(subgroup_evals.height());
+    let g = Val::two_adic_generator(bits);
+    let subgroup = cyclic_subgroup_known_order::<Val>(g, 1 << bits).collect::<Vec<_>>();
+    let weights = barycentric_weights(&subgroup);
+    interpolate_ext(&subgroup, subgroup_evals, x, &weights)
+}
+
+/// Shared implementation backing `eval_at_point` on `NaiveCosetLde` and `Radix2CosetLde`: builds the shifted
+/// coset that `subgroup_evals` is defined over, then evaluates it at `x` via the barycentric
+/// formula. See `eval_subgroup_at_point`.
+pub(crate) fn eval_coset_at_point<Val, EF>(
+    subgroup_evals: &RowMajorMatrix<Val>,
+    shift: Val,
+    x: EF,
+) -> Vec<EF>
+where
+    Val: TwoAdicField,
+    EF: ExtensionField<Val>,
+{
+    let bits = log2_strict_usize(subgroup_evals.height());
+    let g = Val::two_adic_generator(bits);
+    let coset = cyclic_subgroup_coset_known_order(g, shift, 1 << bits).collect::<Vec<_>>();
+    let weights = barycentric_weights(&coset);
+    interpolate_ext(&coset, subgroup_evals, x, &weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::AbstractField;
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::MatrixRows;
+
+    use super::*;
+    use crate::naive::{NaiveCosetLde, NaiveSubgroupLde};
+    use crate::{TwoAdicCosetLde, TwoAdicLde};
+
+    #[test]
+    fn eval_subgroup_at_point_matches_naive_at_domain_point() {
+        let polys = RowMajorMatrix::new(
+            [1, 2, 3, 4]
+                .into_iter()
+                .map(Goldilocks::from_canonical_u64)
+                .collect(),
+            1,
+        );
+        let subgroup_evals = NaiveSubgroupLde.lde_batch(polys, 1);
+
+        let g = Goldilocks::two_adic_generator(log2_strict_usize(subgroup_evals.height()));
+        for i in 0..subgroup_evals.height() {
+            let x = g.exp_u64(i as u64);
+            let expected: Vec<Goldilocks> = subgroup_evals.row(i).into_iter().collect();
+            let actual = eval_subgroup_at_point(&subgroup_evals, x);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn eval_coset_at_point_matches_naive_at_domain_point() {
+        let polys = RowMajorMatrix::new(
+            [1, 2, 3, 4]
+                .into_iter()
+                .map(Goldilocks::from_canonical_u64)
+                .collect(),
+            1,
+        );
+        let shift = NaiveCosetLde.shift(log2_strict_usize(polys.height()) + 1);
+        let subgroup_evals = NaiveCosetLde.lde_batch(polys, 1);
+
+        let bits = log2_strict_usize(subgroup_evals.height());
+        let g = Goldilocks::two_adic_generator(bits);
+        for i in 0..subgroup_evals.height() {
+            let x = shift * g.exp_u64(i as u64);
+            let expected: Vec<Goldilocks> = subgroup_evals.row(i).into_iter().collect();
+            let actual = eval_coset_at_point(&subgroup_evals, shift, x);
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_field::{batch_multiplicative_inverse, scale_vec, sum_vecs, ExtensionField, Field};
+use p3_matrix::MatrixRows;
+
+/// The barycentric weight `w_i = 1 / prod_{j != i} (x_i - x_j)` for each of the given points.
+pub fn barycentric_weights<F: Field>(points: &[F]) -> Vec<F> {
+    let n = points.len();
+    batch_multiplicative_inverse(
+        &(0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| points[i] - points[j])
+                    .product::<F>()
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Evaluates, via the barycentric formula, the unique polynomial of degree `< points.len()`
+/// that interpolates `(points[i], values.row(i))` for each `i`, at the point `x`.
+pub fn interpolate<F: Field, Mat: MatrixRows<F>>(
+    points: &[F],
+    values: &Mat,
+    x: F,
+    barycentric_weights: &[F],
+) -> Vec<F> {
+    // If x is in the list of points, the Lagrange formula would divide by zero.
+    for (i, &x_i) in points.iter().enumerate() {
+        if x_i == x {
+            return values.row(i).into_iter().collect();
+        }
+    }
+
+    let l_x: F = points.iter().map(|&x_i| x - x_i).product();
+
+    let sum = sum_vecs((0..points.len()).map(|i| {
+        let x_i = points[i];
+        let y_i = values.row(i).into_iter().collect();
+        let w_i = barycentric_weights[i];
+        scale_vec(w_i / (x - x_i), y_i)
+    }));
+
+    scale_vec(l_x, sum)
+}
+
+/// Like `interpolate`, but the query point `x` (and hence the result) may live in an extension
+/// field `EF` of the base field `F` that `points`/`values` are defined over. This lets a verifier
+/// open a base-field polynomial at a random out-of-domain challenge, as used in DEEP-style
+/// quotienting, without lifting `values` into `EF` up front.
+pub fn interpolate_ext<F: Field, EF: ExtensionField<F>, Mat: MatrixRows<F>>(
+    points: &[F],
+    values: &Mat,
+    x: EF,
+    barycentric_weights: &[F],
+) -> Vec<EF> {
+    // If x is in the list of points, the Lagrange formula would divide by zero.
+    for (i, &x_i) in points.iter().enumerate() {
+        if EF::from_base(x_i) == x {
+            return values.row(i).into_iter().map(EF::from_base).collect();
+        }
+    }
+
+    let l_x: EF = points.iter().map(|&x_i| x - EF::from_base(x_i)).product();
+
+    let sum = sum_vecs((0..points.len()).map(|i| {
+        let x_i = points[i];
+        let y_i = values.row(i).into_iter().map(EF::from_base).collect();
+        let w_i = barycentric_weights[i];
+        scale_vec(EF::from_base(w_i) / (x - EF::from_base(x_i)), y_i)
+    }));
+
+    scale_vec(l_x, sum)
+}
+
+/// Returns the coefficients (lowest degree first) of the unique polynomial of degree
+/// `< points.len()` satisfying `p(points[i]) == values[i]` for each `i`.
+///
+/// Unlike `interpolate`, which evaluates the interpolating polynomial at a single point, this
+/// recovers its coefficient form in full, e.g. for committing to it or for further algebraic
+/// manipulation.
+///
+/// Panics if `points` contains a duplicate.
+pub fn lagrange_interpolate<F: Field>(points: &[F], values: &[F]) -> Vec<F> {
+    assert_eq!(points.len(), values.len());
+    let n = points.len();
+    if n == 1 {
+        return vec![values[0]];
+    }
+    for i in 0..n {
+        for j in (i + 1)..n {
+            assert_ne!(points[i], points[j], "lagrange_interpolate: duplicate point");
+        }
+    }
+
+    let weights = barycentric_weights(points);
+
+    // The full product polynomial `prod_j (X - x_j)`, coefficients lowest degree first.
+    let mut product = vec![F::one()];
+    for &x_j in points {
+        product = poly_mul_linear(&product, x_j);
+    }
+
+    let mut coeffs = vec![F::zero(); n];
+    for i in 0..n {
+        // `l_i = product / (X - x_i)`, i.e. the product with the `(X - x_i)` factor removed.
+        let l_i = poly_div_linear(&product, points[i]);
+        let scale = weights[i] * values[i];
+        for (c, l) in coeffs.iter_mut().zip(l_i) {
+            *c += scale * l;
+        }
+    }
+    coeffs
+}
+
+/// Multiplies a polynomial (coefficients lowest degree first) by the linear factor `(X - root)`.
+fn poly_mul_linear<F: Field>(coeffs: &[F], root: F) -> Vec<F> {
+    let mut result = vec![F::zero(); coeffs.len() + 1];
+    result[0] = -root * coeffs[0];
+    for k in 1..coeffs.len() {
+        result[k] = coeffs[k - 1] - root * coeffs[k];
+    }
+    result[coeffs.len()] = coeffs[coeffs.len() - 1];
+    result
+}
+
+/// Divides a polynomial (coefficients lowest degree first) by the linear factor `(X - root)`,
+/// via synthetic division. Assumes `root` is in fact a root of `coeffs`, so the remainder is
+/// zero.
+fn poly_div_linear<F: Field>(coeffs: &[F], root: F) -> Vec<F> {
+    let n = coeffs.len() - 1;
+    let mut quotient = vec![F::zero(); n];
+    quotient[n - 1] = coeffs[n];
+    for k in (1..n).rev() {
+        quotient[k - 1] = coeffs[k] + root * quotient[k];
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use p3_field::AbstractField;
+    use p3_goldilocks::Goldilocks;
+
+    use p3_matrix::dense::RowMajorMatrix;
+
+    use super::{barycentric_weights, interpolate, interpolate_ext, lagrange_interpolate};
+
+    fn f(x: u64) -> Goldilocks {
+        Goldilocks::from_canonical_u64(x)
+    }
+
+    #[test]
+    fn lagrange_interpolate_recovers_known_coeffs() {
+        // p(X) = 2 + 3X + 5X^2, evaluated at X = 0, 1, 2.
+        let points = [f(0), f(1), f(2)];
+        let values = [f(2), f(10), f(28)];
+        let coeffs = lagrange_interpolate(&points, &values);
+        assert_eq!(coeffs, vec![f(2), f(3), f(5)]);
+    }
+
+    #[test]
+    fn lagrange_interpolate_single_point() {
+        let coeffs = lagrange_interpolate(&[f(7)], &[f(42)]);
+        assert_eq!(coeffs, vec![f(42)]);
+    }
+
+    #[test]
+    fn lagrange_interpolate_matches_evaluations() {
+        // p(X) = 1 - X + 4X^3, evaluated at X = 1, 2, 3, 4.
+        let coeffs_in = [f(1), -f(1), f(0), f(4)];
+        let eval_at = |x: Goldilocks| {
+            coeffs_in
+                .iter()
+                .rev()
+                .fold(Goldilocks::zero(), |acc, &c| acc * x + c)
+        };
+        let points = [f(1), f(2), f(3), f(4)];
+        let values: Vec<Goldilocks> = points.iter().map(|&x| eval_at(x)).collect();
+
+        let coeffs_out = lagrange_interpolate(&points, &values);
+        assert_eq!(coeffs_out, coeffs_in);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lagrange_interpolate_rejects_duplicate_points() {
+        lagrange_interpolate(&[f(1), f(1)], &[f(2), f(3)]);
+    }
+
+    #[test]
+    fn interpolate_ext_matches_interpolate_at_domain_point() {
+        // `Goldilocks` is trivially its own extension field, which is enough to exercise
+        // `interpolate_ext`'s formula (including its early-return branch) without pulling in a
+        // separate extension field type.
+        let points = [f(0), f(1), f(2)];
+        let values = RowMajorMatrix::new(vec![f(2), f(10), f(28)], 1);
+        let weights = barycentric_weights(&points);
+
+        for &x in &points {
+            let expected = interpolate(&points, &values, x, &weights);
+            let actual: Vec<Goldilocks> = interpolate_ext(&points, &values, x, &weights);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn interpolate_ext_matches_interpolate_off_domain() {
+        let points = [f(0), f(1), f(2)];
+        let values = RowMajorMatrix::new(vec![f(2), f(10), f(28)], 1);
+        let weights = barycentric_weights(&points);
+        let x = f(5);
+
+        let expected = interpolate(&points, &values, x, &weights);
+        let actual: Vec<Goldilocks> = interpolate_ext(&points, &values, x, &weights);
+        assert_eq!(actual, expected);
+    }
+}
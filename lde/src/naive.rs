@@ -1,14 +1,16 @@
 use alloc::vec::Vec;
 
 use p3_field::{
-    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, cyclic_subgroup_known_order,
-    scale_vec, sum_vecs, Field, TwoAdicField,
+    cyclic_subgroup_coset_known_order, cyclic_subgroup_known_order, ExtensionField, Field,
+    TwoAdicField,
 };
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::stack::VerticalPair;
 use p3_matrix::{Matrix, MatrixRows};
 use p3_util::log2_strict_usize;
 
+use crate::eval::{eval_coset_at_point, eval_subgroup_at_point};
+use crate::interpolation::{barycentric_weights, interpolate};
 use crate::{TwoAdicCosetLde, TwoAdicLde, TwoAdicSubgroupLde, UndefinedLde};
 
 /// A naive quadratic-time implementation of `Lde`, intended for testing.
@@ -100,43 +102,27 @@ where
     }
 }
 
-// TODO: Move to interpolation crate?
-fn barycentric_weights<F: Field>(points: &[F]) -> Vec<F> {
-    let n = points.len();
-    batch_multiplicative_inverse(
-        &(0..n)
-            .map(|i| {
-                (0..n)
-                    .filter(|&j| j != i)
-                    .map(|j| points[i] - points[j])
-                    .product::<F>()
-            })
-            .collect::<Vec<_>>(),
-    )
+impl NaiveSubgroupLde {
+    /// Evaluates `subgroup_evals` (the columns of a subgroup LDE) at an out-of-domain point `x`
+    /// drawn from an extension field, without materializing the full LDE.
+    pub fn eval_at_point<Val, EF>(&self, subgroup_evals: &RowMajorMatrix<Val>, x: EF) -> Vec<EF>
+    where
+        Val: TwoAdicField,
+        EF: ExtensionField<Val>,
+    {
+        eval_subgroup_at_point(subgroup_evals, x)
+    }
 }
 
-// TODO: Move to interpolation crate?
-fn interpolate<F: Field, Mat: MatrixRows<F>>(
-    points: &[F],
-    values: &Mat,
-    x: F,
-    barycentric_weights: &[F],
-) -> Vec<F> {
-    // If x is in the list of points, the Lagrange formula would divide by zero.
-    for (i, &x_i) in points.iter().enumerate() {
-        if x_i == x {
-            return values.row(i).into_iter().collect();
-        }
+impl NaiveCosetLde {
+    /// Evaluates `subgroup_evals` (the columns of a coset LDE) at an out-of-domain point `x`
+    /// drawn from an extension field, without materializing the full LDE.
+    pub fn eval_at_point<Val, EF>(&self, subgroup_evals: &RowMajorMatrix<Val>, x: EF) -> Vec<EF>
+    where
+        Val: TwoAdicField,
+        EF: ExtensionField<Val>,
+    {
+        let bits = log2_strict_usize(subgroup_evals.height());
+        eval_coset_at_point(subgroup_evals, self.shift(bits), x)
     }
-
-    let l_x: F = points.iter().map(|&x_i| x - x_i).product();
-
-    let sum = sum_vecs((0..points.len()).map(|i| {
-        let x_i = points[i];
-        let y_i = values.row(i).into_iter().collect();
-        let w_i = barycentric_weights[i];
-        scale_vec(w_i / (x - x_i), y_i)
-    }));
-
-    scale_vec(l_x, sum)
 }
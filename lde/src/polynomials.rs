@@ -0,0 +1,143 @@
+use core::marker::PhantomData;
+
+use p3_field::TwoAdicField;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_util::log2_strict_usize;
+
+use crate::radix2::{
+    coeffs_to_evals, evals_to_coeffs, matrix_to_values, scale_coeffs_by_shift_powers,
+    zero_pad_coeffs,
+};
+
+/// A basis in which a batch of polynomials can be represented.
+///
+/// This mirrors the `Coeff` / `LagrangeCoeff` / `ExtendedLagrangeCoeff` markers used by other
+/// polynomial libraries: tagging a `Polynomials` value with its basis at the type level means an
+/// LDE is a typed conversion between bases rather than an opaque matrix-to-matrix operation, so
+/// callers can't accidentally feed coefficient-form data into code that expects evaluations (or
+/// vice versa).
+pub trait Basis {}
+
+/// Monomial coefficients.
+#[derive(Debug)]
+pub struct Coeff;
+impl Basis for Coeff {}
+
+/// Evaluations over the order-`2^bits` two-adic subgroup, for whatever `bits` the polynomials
+/// were constructed or extended at.
+#[derive(Debug)]
+pub struct Subgroup;
+impl Basis for Subgroup {}
+
+/// Evaluations over a shifted coset of the order-`2^bits` two-adic subgroup.
+#[derive(Debug)]
+pub struct Coset;
+impl Basis for Coset {}
+
+/// A batch of polynomials (one per column of the underlying matrix), tagged with the `Basis` its
+/// values are expressed in.
+#[derive(Debug)]
+pub struct Polynomials<F, B: Basis> {
+    values: RowMajorMatrix<F>,
+    _basis: PhantomData<B>,
+}
+
+impl<F, B: Basis> Polynomials<F, B> {
+    pub fn new(values: RowMajorMatrix<F>) -> Self {
+        Self {
+            values,
+            _basis: PhantomData,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.values.width()
+    }
+
+    pub fn into_inner(self) -> RowMajorMatrix<F> {
+        self.values
+    }
+}
+
+impl<F: TwoAdicField> Polynomials<F, Coeff> {
+    /// Extends these coefficients to evaluations over the order-`2^lde_bits` two-adic subgroup,
+    /// via a forward NTT.
+    pub fn to_subgroup_evals(self, lde_bits: usize) -> Polynomials<F, Subgroup> {
+        let width = self.values.width();
+        let padded = zero_pad_coeffs(matrix_to_values(self.values), width, lde_bits);
+        Polynomials::new(coeffs_to_evals(padded, width, lde_bits))
+    }
+
+    /// Extends these coefficients to evaluations over a coset, shifted by `shift`, of the
+    /// order-`2^lde_bits` two-adic subgroup.
+    pub fn to_coset_evals(self, lde_bits: usize, shift: F) -> Polynomials<F, Coset> {
+        let width = self.values.width();
+        let mut padded = zero_pad_coeffs(matrix_to_values(self.values), width, lde_bits);
+        scale_coeffs_by_shift_powers(&mut padded, width, shift);
+        Polynomials::new(coeffs_to_evals(padded, width, lde_bits))
+    }
+}
+
+impl<F: TwoAdicField> Polynomials<F, Subgroup> {
+    /// Recovers the coefficient form of these polynomials via an inverse NTT.
+    pub fn to_coeffs(self) -> Polynomials<F, Coeff> {
+        let width = self.values.width();
+        let bits = log2_strict_usize(self.values.height());
+        Polynomials::new(RowMajorMatrix::new(evals_to_coeffs(self.values, bits), width))
+    }
+
+    /// Extends this batch onto a larger subgroup. Equivalent to `TwoAdicSubgroupLde::lde_batch`,
+    /// expressed as a `Subgroup -> Subgroup` basis conversion.
+    pub fn lde_to_subgroup(self, added_bits: usize) -> Polynomials<F, Subgroup> {
+        let lde_bits = log2_strict_usize(self.values.height()) + added_bits;
+        self.to_coeffs().to_subgroup_evals(lde_bits)
+    }
+
+    /// Extends this batch onto a coset of a larger subgroup. Equivalent to
+    /// `TwoAdicCosetLde::lde_batch`, expressed as a `Subgroup -> Coset` basis conversion.
+    pub fn lde_to_coset(self, added_bits: usize, shift: F) -> Polynomials<F, Coset> {
+        let lde_bits = log2_strict_usize(self.values.height()) + added_bits;
+        self.to_coeffs().to_coset_evals(lde_bits, shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::AbstractField;
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::dense::RowMajorMatrix;
+    use p3_util::log2_strict_usize;
+
+    use super::{Polynomials, Subgroup};
+    use crate::radix2::Radix2SubgroupLde;
+    use crate::TwoAdicLde;
+
+    fn evals(values: &[u64]) -> RowMajorMatrix<Goldilocks> {
+        RowMajorMatrix::new(
+            values
+                .iter()
+                .map(|&x| Goldilocks::from_canonical_u64(x))
+                .collect(),
+            1,
+        )
+    }
+
+    #[test]
+    fn coeffs_then_subgroup_evals_round_trips() {
+        let original = evals(&[1, 2, 3, 4]);
+        let bits = log2_strict_usize(original.height());
+        let polys = Polynomials::<Goldilocks, Subgroup>::new(original.clone());
+        let round_tripped = polys.to_coeffs().to_subgroup_evals(bits).into_inner();
+        assert_eq!(round_tripped, original);
+    }
+
+    #[test]
+    fn lde_to_subgroup_matches_radix2_lde_batch() {
+        let original = evals(&[1, 2, 3, 4]);
+        let expected = Radix2SubgroupLde.lde_batch(original.clone(), 2);
+        let polys = Polynomials::<Goldilocks, Subgroup>::new(original);
+        let actual = polys.lde_to_subgroup(2).into_inner();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+
+use p3_field::{batch_multiplicative_inverse, ExtensionField, Field, TwoAdicField};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::{Matrix, MatrixRows};
+use p3_util::log2_strict_usize;
+
+use crate::eval::{eval_coset_at_point, eval_subgroup_at_point};
+use crate::{TwoAdicCosetLde, TwoAdicLde, TwoAdicSubgroupLde};
+
+/// A radix-2 FFT-based implementation of `TwoAdicLde`, taking the LDE from the quadratic time of
+/// `NaiveSubgroupLde` down to `O(n log n)`.
+#[derive(Debug)]
+pub struct Radix2SubgroupLde;
+
+/// A radix-2 FFT-based implementation of `TwoAdicLde` over a shifted coset, taking the LDE from
+/// the quadratic time of `NaiveCosetLde` down to `O(n log n)`.
+#[derive(Debug)]
+pub struct Radix2CosetLde;
+
+impl<Val> TwoAdicLde<Val> for Radix2SubgroupLde
+where
+    Val: TwoAdicField,
+{
+    fn lde_batch(&self, polys: RowMajorMatrix<Val>, added_bits: usize) -> RowMajorMatrix<Val> {
+        let width = polys.width();
+        let bits = log2_strict_usize(polys.height());
+        let lde_bits = bits + added_bits;
+
+        let coeffs = evals_to_coeffs(polys, bits);
+        let padded = zero_pad_coeffs(coeffs, width, lde_bits);
+        coeffs_to_evals(padded, width, lde_bits)
+    }
+}
+
+impl<Val> TwoAdicLde<Val> for Radix2CosetLde
+where
+    Val: TwoAdicField,
+{
+    fn lde_batch(&self, polys: RowMajorMatrix<Val>, added_bits: usize) -> RowMajorMatrix<Val> {
+        let width = polys.width();
+        let bits = log2_strict_usize(polys.height());
+        let lde_bits = bits + added_bits;
+
+        let coeffs = evals_to_coeffs(polys, bits);
+        let mut padded = zero_pad_coeffs(coeffs, width, lde_bits);
+        scale_coeffs_by_shift_powers(&mut padded, width, self.shift(lde_bits));
+        coeffs_to_evals(padded, width, lde_bits)
+    }
+}
+
+impl<Val> TwoAdicSubgroupLde<Val> for Radix2SubgroupLde where Val: TwoAdicField {}
+
+impl<Val> TwoAdicCosetLde<Val> for Radix2CosetLde
+where
+    Val: TwoAdicField,
+{
+    fn shift(&self, _lde_bits: usize) -> Val {
+        Val::generator()
+    }
+}
+
+impl Radix2SubgroupLde {
+    /// Evaluates `subgroup_evals` (the columns of a subgroup LDE) at an out-of-domain point `x`
+    /// drawn from an extension field, without materializing the full LDE.
+    pub fn eval_at_point<Val, EF>(&self, subgroup_evals: &RowMajorMatrix<Val>, x: EF) -> Vec<EF>
+    where
+        Val: TwoAdicField,
+        EF: ExtensionField<Val>,
+    {
+        eval_subgroup_at_point(subgroup_evals, x)
+    }
+}
+
+impl Radix2CosetLde {
+    /// Evaluates `subgroup_evals` (the columns of a coset LDE) at an out-of-domain point `x`
+    /// drawn from an extension field, without materializing the full LDE.
+    pub fn eval_at_point<Val, EF>(&self, subgroup_evals: &RowMajorMatrix<Val>, x: EF) -> Vec<EF>
+    where
+        Val: TwoAdicField,
+        EF: ExtensionField<Val>,
+    {
+        let bits = log2_strict_usize(subgroup_evals.height());
+        eval_coset_at_point(subgroup_evals, self.shift(bits), x)
+    }
+}
+
+/// Flattens a matrix's rows, in order, into a single row-major buffer of length
+/// `height * width`.
+pub(crate) fn matrix_to_values<F: Field, Mat: MatrixRows<F>>(mat: Mat) -> Vec<F> {
+    (0..mat.height())
+        .flat_map(|r| mat.row(r).into_iter().collect::<Vec<_>>())
+        .collect()
+}
+
+/// Converts batched evaluations over the order-`2^bits` two-adic subgroup into coefficient form,
+/// via a batched inverse decimation-in-time NTT.
+pub(crate) fn evals_to_coeffs<Val: TwoAdicField>(
+    evals: RowMajorMatrix<Val>,
+    bits: usize,
+) -> Vec<Val> {
+    let width = evals.width();
+    let n = 1usize << bits;
+    let mut values = matrix_to_values(evals);
+
+    bit_reverse_rows(&mut values, width, bits);
+    let g_inv = Val::two_adic_generator(bits).inverse();
+    ntt_rows(&mut values, width, bits, g_inv);
+
+    let n_inv = batch_multiplicative_inverse(&[Val::from_canonical_usize(n)])[0];
+    for v in values.iter_mut() {
+        *v *= n_inv;
+    }
+    values
+}
+
+/// Zero-pads a row-major buffer of coefficients from `2^bits` rows up to `2^lde_bits` rows.
+pub(crate) fn zero_pad_coeffs<Val: Field>(
+    mut coeffs: Vec<Val>,
+    width: usize,
+    lde_bits: usize,
+) -> Vec<Val> {
+    coeffs.resize((1usize << lde_bits) * width, Val::zero());
+    coeffs
+}
+
+/// Scales the `i`-th row of coefficients by `shift^i`, so a subsequent forward NTT lands on the
+/// coset `shift * <g_lde>` rather than the subgroup `<g_lde>`.
+pub(crate) fn scale_coeffs_by_shift_powers<Val: Field>(
+    coeffs: &mut [Val],
+    width: usize,
+    shift: Val,
+) {
+    let mut weight = Val::one();
+    for row in coeffs.chunks_mut(width) {
+        for x in row.iter_mut() {
+            *x *= weight;
+        }
+        weight *= shift;
+    }
+}
+
+/// Runs a forward NTT on a row-major buffer of coefficients (`2^lde_bits` rows), yielding
+/// evaluations over the order-`2^lde_bits` two-adic subgroup.
+pub(crate) fn coeffs_to_evals<Val: TwoAdicField>(
+    mut coeffs: Vec<Val>,
+    width: usize,
+    lde_bits: usize,
+) -> RowMajorMatrix<Val> {
+    bit_reverse_rows(&mut coeffs, width, lde_bits);
+    let g_lde = Val::two_adic_generator(lde_bits);
+    ntt_rows(&mut coeffs, width, lde_bits, g_lde);
+    RowMajorMatrix::new(coeffs, width)
+}
+
+/// Reorders the rows of a row-major buffer (`width` field elements per row) into bit-reversed
+/// order, as required before an in-place Cooley-Tukey NTT.
+fn bit_reverse_rows<Val: Clone>(values: &mut [Val], width: usize, bits: usize) {
+    let n = 1usize << bits;
+    for i in 0..n {
+        let j = reverse_bits(i, bits);
+        if j > i {
+            let (lo, hi) = values.split_at_mut(j * width);
+            lo[i * width..i * width + width].swap_with_slice(&mut hi[..width]);
+        }
+    }
+}
+
+fn reverse_bits(x: usize, bits: usize) -> usize {
+    let mut x = x;
+    let mut rev = 0;
+    for _ in 0..bits {
+        rev = (rev << 1) | (x & 1);
+        x >>= 1;
+    }
+    rev
+}
+
+/// An in-place, iterative decimation-in-time Cooley-Tukey NTT over a row-major buffer whose rows
+/// are already in bit-reversed order. All `width` columns of each row are transformed together,
+/// so twiddle factors are computed once and shared across the whole batch.
+fn ntt_rows<Val: TwoAdicField>(values: &mut [Val], width: usize, bits: usize, root: Val) {
+    let n = 1usize << bits;
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let len_root = root.exp_u64((n / len) as u64);
+        let mut start = 0;
+        while start < n {
+            let mut w = Val::one();
+            for j in 0..half {
+                let u_row = (start + j) * width;
+                let v_row = (start + half + j) * width;
+                for c in 0..width {
+                    let u = values[u_row + c];
+                    let v = values[v_row + c] * w;
+                    values[u_row + c] = u + v;
+                    values[v_row + c] = u - v;
+                }
+                w *= len_root;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::distributions::Standard;
+    use rand::{thread_rng, Rng};
+
+    use super::{Radix2CosetLde, Radix2SubgroupLde};
+    use crate::naive::{NaiveCosetLde, NaiveSubgroupLde};
+    use crate::TwoAdicLde;
+
+    fn rand_matrix(rows: usize, cols: usize) -> RowMajorMatrix<Goldilocks> {
+        let values = thread_rng()
+            .sample_iter(Standard)
+            .take(rows * cols)
+            .collect();
+        RowMajorMatrix::new(values, cols)
+    }
+
+    #[test]
+    fn subgroup_lde_matches_naive() {
+        for &height in &[1, 2, 4, 8] {
+            for &width in &[1, 2, 5] {
+                for added_bits in 0..3 {
+                    let polys = rand_matrix(height, width);
+                    let expected = NaiveSubgroupLde.lde_batch(polys.clone(), added_bits);
+                    let actual = Radix2SubgroupLde.lde_batch(polys, added_bits);
+                    assert_eq!(
+                        actual, expected,
+                        "height={height}, width={width}, added_bits={added_bits}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coset_lde_matches_naive() {
+        for &height in &[1, 2, 4, 8] {
+            for &width in &[1, 2, 5] {
+                for added_bits in 0..3 {
+                    let polys = rand_matrix(height, width);
+                    let expected = NaiveCosetLde.lde_batch(polys.clone(), added_bits);
+                    let actual = Radix2CosetLde.lde_batch(polys, added_bits);
+                    assert_eq!(
+                        actual, expected,
+                        "height={height}, width={width}, added_bits={added_bits}"
+                    );
+                }
+            }
+        }
+    }
+}